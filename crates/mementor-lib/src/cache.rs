@@ -54,8 +54,8 @@ impl DataCache {
     /// git tree if not already cached.
     pub async fn transcript(&mut self, blob_path: &str) -> Result<&[TranscriptEntry]> {
         if !self.transcripts.contains_key(blob_path) {
-            let bytes = tree::show_blob("entire/checkpoints/v1", blob_path).await?;
-            let entries = transcript::parse_transcript(&bytes)?;
+            let bytes = tree::show_blob(checkpoint::BRANCH, blob_path).await?;
+            let entries = transcript::parse_transcript(bytes.as_slice())?;
             self.transcripts.insert(blob_path.to_owned(), entries);
         }
 
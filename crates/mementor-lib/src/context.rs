@@ -54,6 +54,20 @@ impl MementorContext {
     pub fn is_linked_worktree(&self) -> bool {
         self.is_linked_worktree
     }
+
+    /// Entire-cli settings files under the project root, in the order
+    /// entire-cli reads them (later files override earlier ones).
+    pub fn entire_settings_files(&self) -> [PathBuf; 2] {
+        let dir = self.project_root.join(".entire");
+        [dir.join("settings.json"), dir.join("settings.local.json")]
+    }
+
+    /// Claude Code project settings files under the project root, where
+    /// entire-cli registers its hooks.
+    pub fn claude_settings_files(&self) -> [PathBuf; 2] {
+        let dir = self.project_root.join(".claude");
+        [dir.join("settings.json"), dir.join("settings.local.json")]
+    }
 }
 
 #[cfg(test)]
@@ -78,6 +92,38 @@ mod tests {
         assert!(ctx.is_linked_worktree());
     }
 
+    #[test]
+    fn entire_settings_files_are_under_project_root() {
+        let ctx = MementorContext::with_cwd(
+            PathBuf::from("/tmp/worktree"),
+            PathBuf::from("/tmp/project"),
+            true,
+        );
+        assert_eq!(
+            ctx.entire_settings_files(),
+            [
+                PathBuf::from("/tmp/project/.entire/settings.json"),
+                PathBuf::from("/tmp/project/.entire/settings.local.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn claude_settings_files_are_under_project_root() {
+        let ctx = MementorContext::with_cwd(
+            PathBuf::from("/tmp/worktree"),
+            PathBuf::from("/tmp/project"),
+            true,
+        );
+        assert_eq!(
+            ctx.claude_settings_files(),
+            [
+                PathBuf::from("/tmp/project/.claude/settings.json"),
+                PathBuf::from("/tmp/project/.claude/settings.local.json"),
+            ]
+        );
+    }
+
     #[test]
     fn is_linked_worktree_defaults_to_false() {
         let ctx = MementorContext::new(PathBuf::from("/tmp/project"));
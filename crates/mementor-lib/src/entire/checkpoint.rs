@@ -4,7 +4,8 @@ use crate::git::tree;
 use crate::model::checkpoint::{RawCheckpointMeta, SessionRef};
 use crate::model::{CheckpointMeta, SessionMeta};
 
-const BRANCH: &str = "entire/checkpoints/v1";
+/// The branch entire-cli writes committed checkpoints to.
+pub const BRANCH: &str = "entire/checkpoints/v1";
 
 /// List all checkpoints on the `entire/checkpoints/v1` branch.
 ///
@@ -1,3 +1,5 @@
+use std::io::BufRead;
+
 use anyhow::{Context, Result};
 use serde_json::Value;
 
@@ -5,24 +7,67 @@ use crate::model::{
     ContentBlock, ConversationSegment, MessageRole, TranscriptEntry, TranscriptMessage,
 };
 
-/// Parse a JSONL transcript file into a sequence of transcript entries.
+/// Parse a JSONL transcript into a sequence of transcript entries.
 ///
 /// Each line is expected to be a JSON object with a `"type"` field that
-/// determines the entry variant.
-pub fn parse_transcript(jsonl: &[u8]) -> Result<Vec<TranscriptEntry>> {
-    let text = std::str::from_utf8(jsonl).context("transcript is not valid UTF-8")?;
+/// determines the entry variant. The transcript is read line by line, so any
+/// [`BufRead`] works: a blob already in memory (`&[u8]`), a file wrapped in a
+/// `BufReader`, or stdin.
+///
+/// Transcripts copied from other machines or edited by hand are tolerated: a
+/// UTF-8 BOM and CRLF line endings are stripped, and a line holding several
+/// back-to-back objects (`{...}{...}`) is split into one entry per object.
+/// Lines that still fail to parse, including lines that are not valid UTF-8,
+/// become [`TranscriptEntry::Malformed`] entries rather than an error, so one
+/// bad line does not hide the rest.
+pub fn parse_transcript(mut reader: impl BufRead) -> Result<Vec<TranscriptEntry>> {
     let mut entries = Vec::new();
-
-    for (i, line) in text.lines().enumerate() {
-        let line = line.trim();
+    let mut buf = Vec::new();
+
+    for i in 0.. {
+        buf.clear();
+        let n = reader
+            .read_until(b'\n', &mut buf)
+            .with_context(|| format!("failed to read transcript line {}", i + 1))?;
+        if n == 0 {
+            break;
+        }
+        let Ok(line) = std::str::from_utf8(&buf) else {
+            tracing::warn!("malformed transcript line {}: invalid UTF-8", i + 1);
+            entries.push(TranscriptEntry::Malformed {
+                line: i + 1,
+                raw: String::from_utf8_lossy(&buf).trim().to_owned(),
+                error: "invalid UTF-8".to_owned(),
+            });
+            continue;
+        };
+        let line = line.trim_start_matches(BOM).trim();
         if line.is_empty() {
             continue;
         }
 
-        let value: Value = serde_json::from_str(line)
-            .with_context(|| format!("failed to parse JSON at line {}", i + 1))?;
-
-        entries.push(parse_entry(&value, line));
+        match serde_json::from_str::<Value>(line) {
+            Ok(value) => entries.push(parse_entry(&value, line)),
+            Err(e) => {
+                let Some(objects) = split_concatenated(line) else {
+                    tracing::warn!("malformed transcript line {}: {e}", i + 1);
+                    entries.push(TranscriptEntry::Malformed {
+                        line: i + 1,
+                        raw: line.to_owned(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                };
+                tracing::debug!(
+                    "split {} concatenated objects at line {}",
+                    objects.len(),
+                    i + 1,
+                );
+                for (raw, value) in objects {
+                    entries.push(parse_entry(&value, raw));
+                }
+            }
+        }
     }
 
     Ok(entries)
@@ -58,6 +103,33 @@ pub fn group_into_segments(entries: &[TranscriptEntry]) -> Vec<ConversationSegme
     segments
 }
 
+/// UTF-8 byte order mark, written by some Windows editors.
+const BOM: char = '\u{feff}';
+
+/// Split a line holding two or more concatenated JSON objects into the raw
+/// text and parsed value of each object.
+///
+/// Object boundaries are found by the streaming deserializer, which tracks
+/// brace nesting and string escapes. Returns `None` unless the entire line is
+/// consumed by at least two objects.
+fn split_concatenated(line: &str) -> Option<Vec<(&str, Value)>> {
+    let mut stream = serde_json::Deserializer::from_str(line).into_iter::<Value>();
+    let mut objects = Vec::new();
+    let mut start = 0;
+
+    while let Some(result) = stream.next() {
+        let value = result.ok()?;
+        if !value.is_object() {
+            return None;
+        }
+        let end = stream.byte_offset();
+        objects.push((line[start..end].trim(), value));
+        start = end;
+    }
+
+    (objects.len() > 1).then_some(objects)
+}
+
 fn parse_entry(value: &Value, raw_line: &str) -> TranscriptEntry {
     let entry_type = value.get("type").and_then(Value::as_str).unwrap_or("");
 
@@ -74,8 +146,8 @@ fn parse_entry(value: &Value, raw_line: &str) -> TranscriptEntry {
 fn parse_user_message(value: &Value) -> TranscriptEntry {
     let msg = &value["message"];
     let content_str = msg["content"].as_str().unwrap_or("");
-    let uuid = msg["uuid"].as_str().unwrap_or("").to_owned();
-    let timestamp = msg["timestamp"].as_str().map(String::from);
+    let uuid = entry_field(value, "uuid").unwrap_or("").to_owned();
+    let timestamp = entry_field(value, "timestamp").map(String::from);
 
     TranscriptEntry::Message(TranscriptMessage {
         role: MessageRole::User,
@@ -87,8 +159,8 @@ fn parse_user_message(value: &Value) -> TranscriptEntry {
 
 fn parse_assistant_message(value: &Value) -> TranscriptEntry {
     let msg = &value["message"];
-    let uuid = msg["uuid"].as_str().unwrap_or("").to_owned();
-    let timestamp = msg["timestamp"].as_str().map(String::from);
+    let uuid = entry_field(value, "uuid").unwrap_or("").to_owned();
+    let timestamp = entry_field(value, "timestamp").map(String::from);
 
     let content_blocks = msg["content"]
         .as_array()
@@ -103,6 +175,15 @@ fn parse_assistant_message(value: &Value) -> TranscriptEntry {
     })
 }
 
+/// Read a string field that Claude Code writes at the top level of an entry
+/// (`uuid`, `timestamp`), falling back to the same key inside `message`.
+fn entry_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .or_else(|| value["message"][key].as_str())
+}
+
 fn parse_content_block(block: &Value) -> Option<ContentBlock> {
     let block_type = block.get("type")?.as_str()?;
 
@@ -267,17 +348,181 @@ mod tests {
         assert!(matches!(&entries[7], TranscriptEntry::Other(s) if s.contains("unknown-type")));
     }
 
+    #[test]
+    fn unknown_content_blocks_are_skipped() {
+        let line = r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"server_tool_use","id":"x"},{"type":"text","text":"kept"},{"kind":"no-type"}]}}"#;
+        let entries = parse_transcript(line.as_bytes()).unwrap();
+        let TranscriptEntry::Message(msg) = &entries[0] else {
+            panic!("expected Message");
+        };
+        assert_eq!(msg.content.len(), 1);
+        assert!(matches!(&msg.content[0], ContentBlock::Text(t) if t == "kept"));
+    }
+
+    #[test]
+    fn unknown_role_entry_is_other() {
+        let line =
+            r#"{"type":"system","subtype":"future","message":{"role":"system","content":"hi"}}"#;
+        let entries = parse_transcript(line.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(&entries[0], TranscriptEntry::Other(raw) if raw == line));
+    }
+
+    #[test]
+    fn known_type_with_unexpected_shapes_does_not_fail() {
+        let jsonl = concat!(
+            r#"{"type":"user","message":{"role":"user","content":42}}"#,
+            "\n",
+            r#"{"type":"assistant","message":{"role":"assistant","content":"plain string"}}"#,
+            "\n",
+            r#"{"type":"pr-link"}"#,
+            "\n",
+        );
+        let entries = parse_transcript(jsonl.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 3);
+        let TranscriptEntry::Message(assistant) = &entries[1] else {
+            panic!("expected Message");
+        };
+        assert!(assistant.content.is_empty());
+        assert!(matches!(
+            &entries[2],
+            TranscriptEntry::PrLink { pr_number: 0, .. }
+        ));
+    }
+
     #[test]
     fn empty_lines_are_skipped() {
-        let input = b"\n\n{\"type\":\"progress\",\"message\":\"hi\"}\n\n";
+        let input: &[u8] = b"\n\n{\"type\":\"progress\",\"message\":\"hi\"}\n\n";
         let entries = parse_transcript(input).unwrap();
         assert_eq!(entries.len(), 1);
     }
 
     #[test]
-    fn invalid_json_returns_error() {
-        let input = b"not json\n";
-        assert!(parse_transcript(input).is_err());
+    fn invalid_json_becomes_malformed_entry() {
+        let input = concat!(
+            r#"{"type":"progress","message":"before"}"#,
+            "\n",
+            "not json\n",
+            r#"{"type":"progress","message":"after"}"#,
+            "\n",
+        );
+        let entries = parse_transcript(input.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 3);
+        let TranscriptEntry::Malformed { line, raw, error } = &entries[1] else {
+            panic!("expected Malformed, got {:?}", entries[1]);
+        };
+        assert_eq!(*line, 2);
+        assert_eq!(raw, "not json");
+        assert_eq!(error, "expected ident at line 1 column 2");
+        assert!(matches!(&entries[2], TranscriptEntry::Progress(_)));
+    }
+
+    #[test]
+    fn invalid_utf8_becomes_malformed_entry() {
+        let input = [
+            br#"{"type":"progress","message":"before"}"#.as_slice(),
+            b"\n\xff\xfe\n",
+            br#"{"type":"progress","message":"after"}"#,
+            b"\n",
+        ]
+        .concat();
+        let entries = parse_transcript(input.as_slice()).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                TranscriptEntry::Progress(r#"{"type":"progress","message":"before"}"#.to_owned()),
+                TranscriptEntry::Malformed {
+                    line: 2,
+                    raw: "\u{fffd}\u{fffd}".to_owned(),
+                    error: "invalid UTF-8".to_owned(),
+                },
+                TranscriptEntry::Progress(r#"{"type":"progress","message":"after"}"#.to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_lines_across_buffer_boundaries() {
+        let reader = std::io::BufReader::with_capacity(8, fixture_jsonl().as_bytes());
+        let entries = parse_transcript(reader).unwrap();
+        assert_eq!(entries.len(), 8);
+    }
+
+    #[test]
+    fn leading_bom_is_stripped() {
+        let input = "\u{feff}{\"type\":\"progress\",\"message\":\"hi\"}\n";
+        let entries = parse_transcript(input.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(
+            matches!(&entries[0], TranscriptEntry::Progress(s) if s == r#"{"type":"progress","message":"hi"}"#)
+        );
+    }
+
+    #[test]
+    fn crlf_line_endings_are_accepted() {
+        let input = concat!(
+            r#"{"type":"user","message":{"role":"user","content":"hi","uuid":"u1"}}"#,
+            "\r\n",
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"hey"}],"uuid":"a1"}}"#,
+            "\r\n",
+        );
+        let entries = parse_transcript(input.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 2);
+        let TranscriptEntry::Message(msg) = &entries[1] else {
+            panic!("expected Message");
+        };
+        assert!(matches!(&msg.content[0], ContentBlock::Text(t) if t == "hey"));
+    }
+
+    #[test]
+    fn concatenated_objects_on_one_line_are_split() {
+        let input = concat!(
+            r#"{"type":"user","message":{"role":"user","content":"a {brace} \"quote\"","uuid":"u1"}}"#,
+            r#" {"type":"progress","message":"}{"}"#,
+            "\n",
+        );
+        let entries = parse_transcript(input.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 2);
+        let TranscriptEntry::Message(msg) = &entries[0] else {
+            panic!("expected Message");
+        };
+        assert!(matches!(&msg.content[0], ContentBlock::Text(t) if t == "a {brace} \"quote\""));
+        assert!(
+            matches!(&entries[1], TranscriptEntry::Progress(s) if s == r#"{"type":"progress","message":"}{"}"#)
+        );
+    }
+
+    #[test]
+    fn concatenated_objects_with_trailing_garbage_are_malformed() {
+        let input = concat!(r#"{"type":"progress"}{"type":"progress"}"#, "oops\n");
+        let entries = parse_transcript(input.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(
+            &entries[0],
+            TranscriptEntry::Malformed { line: 1, raw, .. } if raw == r#"{"type":"progress"}{"type":"progress"}oops"#
+        ));
+    }
+
+    #[test]
+    fn top_level_uuid_and_timestamp() {
+        let line = r#"{"type":"user","uuid":"u-top","timestamp":"2026-02-17T12:14:10.743Z","message":{"role":"user","content":"hi"}}"#;
+        let entries = parse_transcript(line.as_bytes()).unwrap();
+        let TranscriptEntry::Message(msg) = &entries[0] else {
+            panic!("expected Message");
+        };
+        assert_eq!(msg.uuid, "u-top");
+        assert_eq!(msg.timestamp.as_deref(), Some("2026-02-17T12:14:10.743Z"));
+    }
+
+    #[test]
+    fn top_level_fields_take_precedence_over_message() {
+        let line = r#"{"type":"assistant","uuid":"a-top","timestamp":"2026-02-17T12:00:00Z","message":{"role":"assistant","content":[],"uuid":"a-inner","timestamp":"2026-01-01T00:00:00Z"}}"#;
+        let entries = parse_transcript(line.as_bytes()).unwrap();
+        let TranscriptEntry::Message(msg) = &entries[0] else {
+            panic!("expected Message");
+        };
+        assert_eq!(msg.uuid, "a-top");
+        assert_eq!(msg.timestamp.as_deref(), Some("2026-02-17T12:00:00Z"));
     }
 
     #[test]
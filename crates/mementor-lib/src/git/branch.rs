@@ -20,6 +20,14 @@ pub async fn current_branch() -> Result<String> {
     Ok(output.trim().to_owned())
 }
 
+/// Check whether a local branch with the given name exists.
+pub async fn branch_exists(name: &str) -> bool {
+    let ref_name = format!("refs/heads/{name}");
+    git(&["rev-parse", "--verify", "--quiet", &ref_name])
+        .await
+        .is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,6 +38,20 @@ mod tests {
         assert!(!branch.is_empty());
     }
 
+    #[tokio::test]
+    async fn branch_exists_for_current_branch() {
+        let current = current_branch().await.unwrap();
+        // HEAD might be detached, in which case there is no branch to find.
+        if current != "HEAD" {
+            assert!(branch_exists(&current).await);
+        }
+    }
+
+    #[tokio::test]
+    async fn branch_exists_false_for_unknown_branch() {
+        assert!(!branch_exists("no-such-branch/for-mementor-tests").await);
+    }
+
     #[tokio::test]
     async fn list_branches_excludes_entire() {
         let branches = list_branches().await.unwrap();
@@ -306,7 +306,7 @@ index aaa..bbb 100644
 
     #[test]
     fn diff_line_variants() {
-        let hunk_lines = vec![
+        let hunk_lines = [
             DiffLine::Context("context".into()),
             DiffLine::Added("added".into()),
             DiffLine::Removed("removed".into()),
@@ -232,7 +232,7 @@ mod tests {
     // Submodule cases
     // ---------------------------------------------------------------
 
-    /// Helper: create a repo, add a submodule, return (parent_dir, sub_dir).
+    /// Helper: create a repo, add a submodule, return `(parent_dir, sub_dir)`.
     fn setup_repo_with_submodule(tmp: &Path) -> (PathBuf, PathBuf) {
         // Create the "remote" repo that will become the submodule.
         let remote = tmp.join("remote-sub");
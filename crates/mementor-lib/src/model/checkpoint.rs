@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Token usage statistics for a checkpoint or session.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct TokenUsage {
     pub input_tokens: u64,
     pub cache_creation_tokens: u64,
@@ -11,7 +11,7 @@ pub struct TokenUsage {
 }
 
 /// Line attribution for AI vs human contributions.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct Attribution {
     pub agent_lines: u64,
     pub human_added: u64,
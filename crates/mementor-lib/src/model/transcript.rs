@@ -6,7 +6,7 @@ pub enum MessageRole {
 }
 
 /// A content block within an assistant message.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ContentBlock {
     Text(String),
     Thinking(String),
@@ -21,7 +21,7 @@ pub enum ContentBlock {
 }
 
 /// A single message in a transcript.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TranscriptMessage {
     pub role: MessageRole,
     pub uuid: String,
@@ -30,7 +30,7 @@ pub struct TranscriptMessage {
 }
 
 /// An entry in a JSONL transcript file.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TranscriptEntry {
     Message(TranscriptMessage),
     FileHistorySnapshot {
@@ -43,6 +43,18 @@ pub enum TranscriptEntry {
     },
     Progress(String),
     Other(String),
+    /// A line that was not valid UTF-8 or could not be parsed as JSON, kept
+    /// so it can be shown and reported instead of failing the whole
+    /// transcript.
+    Malformed {
+        /// 1-based line number in the JSONL file.
+        line: usize,
+        /// The line as read, trimmed; invalid UTF-8 is replaced with U+FFFD.
+        raw: String,
+        /// Why the line was rejected: the JSON parse error or
+        /// `invalid UTF-8`.
+        error: String,
+    },
 }
 
 /// A group of transcript entries for display in the TUI.
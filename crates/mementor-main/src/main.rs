@@ -1,27 +1,63 @@
 use std::path::Path;
 
+use clap::Parser;
 use mementor_lib::cache::DataCache;
+use mementor_lib::context::MementorContext;
 use mementor_lib::git::branch::current_branch;
 use mementor_lib::git::resolve_worktree;
 use mementor_tui::app::App;
+use mementor_tui::cli::{Cli, Command};
+use mementor_tui::commands;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
     tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
         .init();
 
     let cwd = std::env::current_dir()?;
     let resolved = resolve_worktree(&cwd);
-    let _project_root = resolved
+    let project_root = resolved
         .primary_root()
         .map_or_else(|| cwd.clone(), Path::to_path_buf);
+    let ctx = MementorContext::with_cwd(cwd, project_root, resolved.is_linked());
 
+    let time_style = cli.time_style();
     let branch = current_branch().await.unwrap_or_else(|_| "main".into());
+
+    if let Some(command) = cli.command {
+        let mut stdout = std::io::stdout().lock();
+        return match command {
+            Command::Paths { json } => commands::paths::run(&ctx, json, &mut stdout).await,
+            Command::List {
+                branch: filter,
+                json,
+            } => {
+                commands::list::run(&branch, filter.as_deref(), json, time_style, &mut stdout).await
+            }
+            Command::Show {
+                checkpoint_id,
+                json,
+            } => commands::show::run(&branch, &checkpoint_id, json, time_style, &mut stdout).await,
+            Command::Export {
+                checkpoint_id,
+                session,
+                thinking,
+                tool_results,
+            } => {
+                let opts = commands::export::ExportOptions::new(thinking, tool_results, time_style);
+                commands::export::run(&branch, &checkpoint_id, session, opts, &mut stdout).await
+            }
+        };
+    }
+
     let cache = DataCache::initialize(&branch).await?;
 
     let mut terminal = App::setup_terminal()?;
-    let mut app = App::new(cache, branch);
+    let mut app = App::new(cache, branch, time_style);
 
     let result = app.run(&mut terminal).await;
 
@@ -14,6 +14,7 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::prelude::*;
 use ratatui::widgets::ListState;
 
+use crate::views::time_utils::TimeStyle;
 use crate::views::{branch_popup, dashboard, detail, diff_view, git_log, status_bar, transcript};
 
 /// The active view in the application.
@@ -41,6 +42,8 @@ pub struct App {
     pub branch_popup_open: bool,
     pub branches: Vec<String>,
     pub branch_list_state: ListState,
+    /// How timestamps are rendered in every view.
+    pub time_style: TimeStyle,
 
     // Per-view state.
     pub detail_state: detail::DetailState,
@@ -53,7 +56,7 @@ pub struct App {
 }
 
 impl App {
-    pub fn new(cache: DataCache, branch: String) -> Self {
+    pub fn new(cache: DataCache, branch: String, time_style: TimeStyle) -> Self {
         let mut list_state = ListState::default();
         if !cache.checkpoints().is_empty() {
             list_state.select(Some(0));
@@ -67,6 +70,7 @@ impl App {
             branch_popup_open: false,
             branches: Vec::new(),
             branch_list_state: ListState::default(),
+            time_style,
             detail_state: detail::DetailState::new(0),
             transcript_state: transcript::TranscriptViewState::new(),
             diff_state: diff_view::DiffViewState::new(),
@@ -134,12 +138,19 @@ impl App {
                         &commits,
                         &file_statuses,
                         transcript_ref,
+                        self.time_style,
                     );
                 }
             }
             View::Transcript { .. } => {
                 let entries = self.loaded_transcript.as_deref().unwrap_or(&[]);
-                transcript::render(frame, chunks[0], &mut self.transcript_state, entries);
+                transcript::render(
+                    frame,
+                    chunks[0],
+                    &mut self.transcript_state,
+                    entries,
+                    self.time_style,
+                );
             }
             View::DiffView(hash) => {
                 let header = hash.clone();
@@ -152,7 +163,13 @@ impl App {
             }
             View::GitLog => {
                 let commits = self.cache.commits().to_vec();
-                git_log::render(frame, chunks[0], &mut self.git_log_state, &commits);
+                git_log::render(
+                    frame,
+                    chunks[0],
+                    &mut self.git_log_state,
+                    &commits,
+                    self.time_style,
+                );
             }
         }
         status_bar::render(frame, chunks[1], self);
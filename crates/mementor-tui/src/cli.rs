@@ -0,0 +1,167 @@
+use clap::{Parser, Subcommand};
+
+use crate::views::time_utils::TimeStyle;
+
+/// Command-line arguments for the `mementor` binary.
+#[derive(Debug, Parser)]
+#[command(
+    name = "mementor",
+    version,
+    about = "Browse entire-cli checkpoints in the terminal"
+)]
+pub struct Cli {
+    /// Show absolute ISO 8601 timestamps in UTC instead of relative times.
+    #[arg(long, global = true, conflicts_with = "iso")]
+    pub utc: bool,
+
+    /// Show absolute ISO 8601 timestamps in the local time zone instead of
+    /// relative times.
+    #[arg(long, global = true)]
+    pub iso: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Subcommands. Without one, `mementor` launches the TUI.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print the resolved project root, worktree, checkpoint branch, and
+    /// config files.
+    Paths {
+        /// Print JSON instead of text.
+        #[arg(long)]
+        json: bool,
+    },
+    /// List checkpoints with their sessions, files, and linked commits.
+    List {
+        /// Only list checkpoints recorded on this branch.
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Print JSON instead of text.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show one checkpoint's sessions, token usage, attribution, and commits.
+    Show {
+        /// The checkpoint ID (12 hex characters).
+        checkpoint_id: String,
+
+        /// Print JSON instead of text.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export a checkpoint's transcripts as Markdown.
+    Export {
+        /// The checkpoint ID (12 hex characters).
+        checkpoint_id: String,
+
+        /// Export only this session (1-based, in checkpoint order).
+        #[arg(long)]
+        session: Option<usize>,
+
+        /// Include thinking blocks.
+        #[arg(long)]
+        thinking: bool,
+
+        /// Include tool results, truncated to 20 lines each.
+        #[arg(long)]
+        tool_results: bool,
+    },
+}
+
+impl Cli {
+    /// Resolve the timestamp style selected by `--utc` / `--iso`.
+    pub fn time_style(&self) -> TimeStyle {
+        if self.utc {
+            TimeStyle::Utc
+        } else if self.iso {
+            TimeStyle::Iso
+        } else {
+            TimeStyle::Relative
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_time_style_is_relative() {
+        let cli = Cli::try_parse_from(["mementor"]).unwrap();
+        assert_eq!(cli.time_style(), TimeStyle::Relative);
+    }
+
+    #[test]
+    fn utc_and_iso_flags_select_style() {
+        let cli = Cli::try_parse_from(["mementor", "--utc"]).unwrap();
+        assert_eq!(cli.time_style(), TimeStyle::Utc);
+
+        let cli = Cli::try_parse_from(["mementor", "--iso"]).unwrap();
+        assert_eq!(cli.time_style(), TimeStyle::Iso);
+    }
+
+    #[test]
+    fn time_style_flags_are_accepted_after_subcommand() {
+        let cli = Cli::try_parse_from(["mementor", "list", "--utc"]).unwrap();
+        assert_eq!(cli.time_style(), TimeStyle::Utc);
+
+        let cli = Cli::try_parse_from(["mementor", "show", "d5bd4941cf95", "--iso"]).unwrap();
+        assert_eq!(cli.time_style(), TimeStyle::Iso);
+    }
+
+    #[test]
+    fn paths_subcommand_parses_json_flag() {
+        let cli = Cli::try_parse_from(["mementor", "paths", "--json"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Paths { json: true })));
+    }
+
+    #[test]
+    fn list_subcommand_parses_branch() {
+        let cli = Cli::try_parse_from(["mementor", "list", "--branch", "main"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::List { branch: Some(b), json: false }) if b == "main"
+        ));
+    }
+
+    #[test]
+    fn show_subcommand_requires_checkpoint_id() {
+        assert!(Cli::try_parse_from(["mementor", "show"]).is_err());
+
+        let cli = Cli::try_parse_from(["mementor", "show", "d5bd4941cf95", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Show { checkpoint_id, json: true }) if checkpoint_id == "d5bd4941cf95"
+        ));
+    }
+
+    #[test]
+    fn export_subcommand_parses_options() {
+        let cli = Cli::try_parse_from([
+            "mementor",
+            "export",
+            "d5bd4941cf95",
+            "--session",
+            "2",
+            "--tool-results",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Export {
+                session: Some(2),
+                thinking: false,
+                tool_results: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn utc_and_iso_conflict() {
+        assert!(Cli::try_parse_from(["mementor", "--utc", "--iso"]).is_err());
+    }
+}
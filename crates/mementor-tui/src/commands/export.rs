@@ -0,0 +1,425 @@
+use std::io::Write;
+
+use anyhow::{Result, bail};
+use mementor_lib::git::log::CommitInfo;
+use mementor_lib::model::{
+    CheckpointMeta, ContentBlock, MessageRole, SessionMeta, TranscriptEntry, TranscriptMessage,
+};
+
+use super::list::linked_commits;
+use super::show::load_checkpoint;
+use crate::views::time_utils::{self, TimeStyle};
+
+/// Maximum characters of tool input shown after the tool name.
+const TOOL_INPUT_WIDTH: usize = 120;
+
+/// Maximum lines of a tool result kept when `--tool-results` is given.
+const TOOL_RESULT_LINES: usize = 20;
+
+/// What to include besides user and assistant text.
+#[derive(Debug, Clone, Copy)]
+pub struct ExportOptions {
+    /// Include thinking blocks as block quotes.
+    pub thinking: bool,
+    /// Include tool results as fenced blocks, truncated to
+    /// [`TOOL_RESULT_LINES`] lines.
+    pub tool_results: bool,
+    pub time_style: TimeStyle,
+}
+
+impl ExportOptions {
+    /// Build options from the CLI flags.
+    ///
+    /// An export is read later and elsewhere, so relative times would go
+    /// stale; [`TimeStyle::Relative`] falls back to UTC.
+    pub fn new(thinking: bool, tool_results: bool, time_style: TimeStyle) -> Self {
+        Self {
+            thinking,
+            tool_results,
+            time_style: match time_style {
+                TimeStyle::Relative => TimeStyle::Utc,
+                absolute => absolute,
+            },
+        }
+    }
+}
+
+/// Write the checkpoint header: id, branch, linked commits, and files.
+pub fn write_header(
+    out: &mut impl Write,
+    cp: &CheckpointMeta,
+    commits: &[CommitInfo],
+) -> Result<()> {
+    writeln!(out, "# Checkpoint {}", cp.checkpoint_id)?;
+    writeln!(out)?;
+    writeln!(out, "- Branch: `{}`", cp.branch)?;
+    for c in linked_commits(cp, commits) {
+        writeln!(out, "- Commit: `{}` {}", c.short_hash, c.subject)?;
+    }
+    if !cp.files_touched.is_empty() {
+        writeln!(out, "- Files touched:")?;
+        for path in &cp.files_touched {
+            writeln!(out, "  - `{path}`")?;
+        }
+    }
+    Ok(())
+}
+
+/// Write one session's transcript as a Markdown section.
+pub fn write_session(
+    out: &mut impl Write,
+    index: usize,
+    session: &SessionMeta,
+    entries: &[TranscriptEntry],
+    opts: ExportOptions,
+) -> Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "## Session {}: {} ({}, {})",
+        index + 1,
+        session.session_id,
+        session.agent,
+        time_utils::format_timestamp(&session.created_at, opts.time_style),
+    )?;
+
+    for entry in entries {
+        match entry {
+            TranscriptEntry::Message(msg) => write_message(out, msg, opts)?,
+            TranscriptEntry::PrLink {
+                pr_number, pr_url, ..
+            } => {
+                writeln!(out)?;
+                writeln!(out, "**PR #{pr_number}:** {pr_url}")?;
+            }
+            TranscriptEntry::Malformed { line, error, .. } => {
+                writeln!(out)?;
+                writeln!(out, "> Malformed transcript line {line}: {error}")?;
+            }
+            TranscriptEntry::FileHistorySnapshot { .. }
+            | TranscriptEntry::Progress(_)
+            | TranscriptEntry::Other(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn write_message(out: &mut impl Write, msg: &TranscriptMessage, opts: ExportOptions) -> Result<()> {
+    // User entries that only carry tool results have no text; skip them
+    // unless their results are exported.
+    let has_visible = msg.content.iter().any(|block| match block {
+        ContentBlock::Text(text) => !text.trim().is_empty(),
+        ContentBlock::Thinking(_) => opts.thinking,
+        ContentBlock::ToolUse { .. } => true,
+        ContentBlock::ToolResult { .. } => opts.tool_results,
+    });
+    if !has_visible {
+        return Ok(());
+    }
+
+    let role = match msg.role {
+        MessageRole::User => "User",
+        MessageRole::Assistant => "Assistant",
+    };
+    writeln!(out)?;
+    match &msg.timestamp {
+        Some(ts) => writeln!(
+            out,
+            "### {role} ({})",
+            time_utils::format_timestamp(ts, opts.time_style)
+        )?,
+        None => writeln!(out, "### {role}")?,
+    }
+
+    for block in &msg.content {
+        match block {
+            ContentBlock::Text(text) if !text.trim().is_empty() => {
+                writeln!(out)?;
+                writeln!(out, "{}", text.trim_end())?;
+            }
+            ContentBlock::Thinking(text) if opts.thinking => {
+                writeln!(out)?;
+                for line in text.trim_end().lines() {
+                    writeln!(out, "> {line}")?;
+                }
+            }
+            ContentBlock::ToolUse { name, input } => {
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "- {} {}",
+                    code_span(name),
+                    code_span(&truncate_chars(&input.to_string(), TOOL_INPUT_WIDTH))
+                )?;
+            }
+            ContentBlock::ToolResult { content, .. } if opts.tool_results => {
+                writeln!(out)?;
+                write_fenced(out, content)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Write `content` in a fenced block, keeping at most [`TOOL_RESULT_LINES`]
+/// lines. The fence is longer than any backtick run in the content.
+fn write_fenced(out: &mut impl Write, content: &str) -> Result<()> {
+    let fence = "`".repeat(longest_backtick_run(content).max(2) + 1);
+    let total = content.lines().count();
+
+    writeln!(out, "{fence}text")?;
+    for line in content.lines().take(TOOL_RESULT_LINES) {
+        writeln!(out, "{line}")?;
+    }
+    if total > TOOL_RESULT_LINES {
+        writeln!(out, "... ({} more lines)", total - TOOL_RESULT_LINES)?;
+    }
+    writeln!(out, "{fence}")?;
+    Ok(())
+}
+
+/// Wrap `s` in an inline code span whose delimiter is longer than any
+/// backtick run in `s`, padding with spaces when `s` starts or ends with a
+/// backtick.
+fn code_span(s: &str) -> String {
+    let ticks = "`".repeat(longest_backtick_run(s) + 1);
+    if s.starts_with('`') || s.ends_with('`') {
+        format!("{ticks} {s} {ticks}")
+    } else {
+        format!("{ticks}{s}{ticks}")
+    }
+}
+
+fn longest_backtick_run(s: &str) -> usize {
+    s.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+fn truncate_chars(s: &str, max: usize) -> String {
+    if s.chars().count() > max {
+        format!("{}...", s.chars().take(max).collect::<String>())
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Run `mementor export <checkpoint-id>`, writing Markdown to `out`.
+///
+/// `session` selects one session by its 1-based position; all sessions are
+/// exported otherwise.
+pub async fn run(
+    current_branch: &str,
+    checkpoint_id: &str,
+    session: Option<usize>,
+    opts: ExportOptions,
+    out: &mut impl Write,
+) -> Result<()> {
+    let (mut cache, cp) = load_checkpoint(current_branch, checkpoint_id).await?;
+
+    let selected: Vec<(usize, &SessionMeta)> = match session {
+        Some(n) => match cp.sessions.get(n.wrapping_sub(1)) {
+            Some(s) => vec![(n - 1, s)],
+            None => bail!(
+                "checkpoint {checkpoint_id} has {} sessions, not {n}",
+                cp.sessions.len()
+            ),
+        },
+        None => cp.sessions.iter().enumerate().collect(),
+    };
+
+    write_header(out, &cp, cache.commits())?;
+    for (index, s) in selected {
+        let entries = cache.transcript(&s.blob_path).await?;
+        write_session(out, index, s, entries, opts)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use mementor_lib::model::{Attribution, TokenUsage};
+
+    use super::*;
+    use crate::commands::list::tests::{sample_checkpoint, sample_commits};
+
+    fn session() -> SessionMeta {
+        SessionMeta {
+            session_id: "s-1".to_owned(),
+            created_at: "2026-02-22T08:20:00Z".to_owned(),
+            agent: "Claude Code".to_owned(),
+            token_usage: TokenUsage::default(),
+            initial_attribution: Attribution::default(),
+            blob_path: String::new(),
+        }
+    }
+
+    fn message(role: MessageRole, content: Vec<ContentBlock>) -> TranscriptEntry {
+        TranscriptEntry::Message(TranscriptMessage {
+            role,
+            uuid: String::new(),
+            timestamp: Some("2026-02-22T08:21:00Z".to_owned()),
+            content,
+        })
+    }
+
+    fn entries() -> Vec<TranscriptEntry> {
+        vec![
+            message(
+                MessageRole::User,
+                vec![ContentBlock::Text("Fix the CI".to_owned())],
+            ),
+            message(
+                MessageRole::Assistant,
+                vec![
+                    ContentBlock::Thinking("Check the workflow".to_owned()),
+                    ContentBlock::Text("Looking at it.".to_owned()),
+                    ContentBlock::ToolUse {
+                        name: "Read".to_owned(),
+                        input: serde_json::json!({ "path": "ci.yml" }),
+                    },
+                ],
+            ),
+            message(
+                MessageRole::User,
+                vec![ContentBlock::ToolResult {
+                    tool_use_id: "t1".to_owned(),
+                    content: "on: push".to_owned(),
+                }],
+            ),
+            TranscriptEntry::PrLink {
+                pr_number: 42,
+                pr_url: "https://github.com/o/r/pull/42".to_owned(),
+                repository: "o/r".to_owned(),
+            },
+        ]
+    }
+
+    fn export(opts: ExportOptions) -> String {
+        let mut out = Vec::new();
+        write_session(&mut out, 0, &session(), &entries(), opts).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn header_lists_branch_commits_and_files() {
+        let mut out = Vec::new();
+        write_header(
+            &mut out,
+            &sample_checkpoint("d5bd4941cf95", "main"),
+            &sample_commits(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "# Checkpoint d5bd4941cf95\n\
+             \n\
+             - Branch: `main`\n\
+             - Commit: `c04a441` redesign schema\n\
+             - Files touched:\n  \
+             - `src/a.rs`\n  \
+             - `src/b.rs`\n",
+        );
+    }
+
+    #[test]
+    fn default_export_omits_thinking_and_tool_results() {
+        let opts = ExportOptions::new(false, false, TimeStyle::Utc);
+
+        assert_eq!(
+            export(opts),
+            "\n## Session 1: s-1 (Claude Code, 2026-02-22T08:20:00Z)\n\
+             \n### User (2026-02-22T08:21:00Z)\n\
+             \nFix the CI\n\
+             \n### Assistant (2026-02-22T08:21:00Z)\n\
+             \nLooking at it.\n\
+             \n- `Read` `{\"path\":\"ci.yml\"}`\n\
+             \n**PR #42:** https://github.com/o/r/pull/42\n",
+        );
+    }
+
+    #[test]
+    fn thinking_and_tool_results_are_included_on_request() {
+        let opts = ExportOptions::new(true, true, TimeStyle::Utc);
+
+        assert_eq!(
+            export(opts),
+            "\n## Session 1: s-1 (Claude Code, 2026-02-22T08:20:00Z)\n\
+             \n### User (2026-02-22T08:21:00Z)\n\
+             \nFix the CI\n\
+             \n### Assistant (2026-02-22T08:21:00Z)\n\
+             \n> Check the workflow\n\
+             \nLooking at it.\n\
+             \n- `Read` `{\"path\":\"ci.yml\"}`\n\
+             \n### User (2026-02-22T08:21:00Z)\n\
+             \n```text\non: push\n```\n\
+             \n**PR #42:** https://github.com/o/r/pull/42\n",
+        );
+    }
+
+    #[test]
+    fn fenced_block_outgrows_backticks_and_truncates() {
+        let content = format!(
+            "````\n{}",
+            (1..=25)
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        let mut out = Vec::new();
+        write_fenced(&mut out, &content).unwrap();
+
+        let body = (1..=19)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("`````text\n````\n{body}\n... (6 more lines)\n`````\n"),
+        );
+    }
+
+    #[test]
+    fn tool_input_with_backticks_gets_longer_code_span() {
+        let entries = [message(
+            MessageRole::Assistant,
+            vec![ContentBlock::ToolUse {
+                name: "Bash".to_owned(),
+                input: serde_json::json!({ "command": "ls *.rs | grep `x`" }),
+            }],
+        )];
+        let mut out = Vec::new();
+        write_session(
+            &mut out,
+            0,
+            &session(),
+            &entries,
+            ExportOptions::new(false, false, TimeStyle::Utc),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\n## Session 1: s-1 (Claude Code, 2026-02-22T08:20:00Z)\n\
+             \n### Assistant (2026-02-22T08:21:00Z)\n\
+             \n- `Bash` ``{\"command\":\"ls *.rs | grep `x`\"}``\n",
+        );
+    }
+
+    #[test]
+    fn code_span_pads_leading_backtick() {
+        assert_eq!(code_span("`a`"), "`` `a` ``");
+    }
+
+    #[test]
+    fn relative_time_style_exports_as_utc() {
+        assert_eq!(
+            ExportOptions::new(false, false, TimeStyle::Relative).time_style,
+            TimeStyle::Utc
+        );
+        assert_eq!(
+            ExportOptions::new(false, false, TimeStyle::Iso).time_style,
+            TimeStyle::Iso
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use std::cmp::Reverse;
+use std::io::Write;
+
+use anyhow::Result;
+use mementor_lib::cache::DataCache;
+use mementor_lib::git::log::CommitInfo;
+use mementor_lib::model::{CheckpointMeta, TokenUsage};
+use serde::Serialize;
+
+use crate::views::time_utils::{self, TimeStyle};
+
+/// All checkpoints, as printed by `mementor list`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CheckpointList {
+    pub checkpoints: Vec<CheckpointSummary>,
+    pub total: usize,
+}
+
+/// One row of `mementor list`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CheckpointSummary {
+    pub checkpoint_id: String,
+    pub branch: String,
+    pub strategy: String,
+    /// Creation time of the first session, if any.
+    pub created_at: Option<String>,
+    pub sessions: Vec<SessionSummary>,
+    pub files_touched: Vec<String>,
+    pub token_usage: TokenUsage,
+    pub commits: Vec<CommitRef>,
+}
+
+/// A session within a [`CheckpointSummary`].
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub agent: String,
+    pub created_at: String,
+}
+
+/// A commit linked to a checkpoint through its `Entire-Checkpoint` trailer.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CommitRef {
+    pub hash: String,
+    pub subject: String,
+}
+
+impl CheckpointList {
+    /// Build the list from loaded checkpoints, keeping only those recorded on
+    /// `branch` when one is given. Checkpoints are sorted newest first;
+    /// those without a session come last.
+    pub fn build(
+        checkpoints: &[CheckpointMeta],
+        commits: &[CommitInfo],
+        branch: Option<&str>,
+    ) -> Self {
+        let mut checkpoints: Vec<_> = checkpoints
+            .iter()
+            .filter(|cp| branch.is_none_or(|b| cp.branch == b))
+            .map(|cp| CheckpointSummary::build(cp, commits))
+            .collect();
+        checkpoints.sort_by_key(|cp| {
+            Reverse(
+                cp.created_at
+                    .as_deref()
+                    .and_then(time_utils::parse_timestamp),
+            )
+        });
+
+        Self {
+            total: checkpoints.len(),
+            checkpoints,
+        }
+    }
+
+    /// Write one line per checkpoint: id, branch, time, agent, file count,
+    /// and the first linked commit subject.
+    pub fn write_text(&self, out: &mut impl Write, time_style: TimeStyle) -> Result<()> {
+        for cp in &self.checkpoints {
+            let created = cp.created_at.as_deref().map_or_else(String::new, |ts| {
+                time_utils::format_timestamp(ts, time_style)
+            });
+            let agent = cp.sessions.first().map_or("unknown", |s| s.agent.as_str());
+            write!(
+                out,
+                "{}  {}  {created}  {agent}  {}",
+                cp.checkpoint_id,
+                cp.branch,
+                plural(cp.files_touched.len(), "file"),
+            )?;
+            match cp.commits.first() {
+                Some(c) => writeln!(out, "  {}", c.subject)?,
+                None => writeln!(out)?,
+            }
+        }
+        writeln!(out, "{}", plural(self.total, "checkpoint"))?;
+        Ok(())
+    }
+
+    /// Write the list as pretty-printed JSON.
+    pub fn write_json(&self, out: &mut impl Write) -> Result<()> {
+        serde_json::to_writer_pretty(&mut *out, self)?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
+
+impl CheckpointSummary {
+    fn build(cp: &CheckpointMeta, commits: &[CommitInfo]) -> Self {
+        Self {
+            checkpoint_id: cp.checkpoint_id.clone(),
+            branch: cp.branch.clone(),
+            strategy: cp.strategy.clone(),
+            created_at: cp.sessions.first().map(|s| s.created_at.clone()),
+            sessions: cp
+                .sessions
+                .iter()
+                .map(|s| SessionSummary {
+                    session_id: s.session_id.clone(),
+                    agent: s.agent.clone(),
+                    created_at: s.created_at.clone(),
+                })
+                .collect(),
+            files_touched: cp.files_touched.clone(),
+            token_usage: cp.token_usage.clone(),
+            commits: linked_commits(cp, commits)
+                .map(|c| CommitRef {
+                    hash: c.short_hash.clone(),
+                    subject: c.subject.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `count` followed by `noun`, with an `s` unless `count` is 1.
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{count} {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+/// Commits from `commits` whose hash was linked to `cp`.
+pub(crate) fn linked_commits<'a>(
+    cp: &'a CheckpointMeta,
+    commits: &'a [CommitInfo],
+) -> impl Iterator<Item = &'a CommitInfo> {
+    cp.commit_hashes
+        .iter()
+        .filter_map(|hash| commits.iter().find(|c| &c.hash == hash))
+}
+
+/// Run `mementor list`, writing to `out`.
+pub async fn run(
+    current_branch: &str,
+    branch: Option<&str>,
+    json: bool,
+    time_style: TimeStyle,
+    out: &mut impl Write,
+) -> Result<()> {
+    let cache = DataCache::initialize(branch.unwrap_or(current_branch)).await?;
+    let list = CheckpointList::build(cache.checkpoints(), cache.commits(), branch);
+    if json {
+        list.write_json(out)
+    } else {
+        list.write_text(out, time_style)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use mementor_lib::model::{Attribution, SessionMeta};
+
+    use super::*;
+
+    pub(crate) fn sample_checkpoint(id: &str, branch: &str) -> CheckpointMeta {
+        CheckpointMeta {
+            checkpoint_id: id.to_owned(),
+            strategy: "manual-commit".to_owned(),
+            branch: branch.to_owned(),
+            files_touched: vec!["src/a.rs".to_owned(), "src/b.rs".to_owned()],
+            sessions: vec![SessionMeta {
+                session_id: "s-1".to_owned(),
+                created_at: "2026-02-22T08:20:00Z".to_owned(),
+                agent: "Claude Code".to_owned(),
+                token_usage: TokenUsage::default(),
+                initial_attribution: Attribution::default(),
+                blob_path: String::new(),
+            }],
+            token_usage: TokenUsage {
+                input_tokens: 100,
+                output_tokens: 20,
+                ..TokenUsage::default()
+            },
+            commit_hashes: vec!["c04a441f".to_owned()],
+        }
+    }
+
+    pub(crate) fn sample_commits() -> Vec<CommitInfo> {
+        vec![CommitInfo {
+            hash: "c04a441f".to_owned(),
+            short_hash: "c04a441".to_owned(),
+            subject: "redesign schema".to_owned(),
+            author: "Alice".to_owned(),
+            date: "2026-02-22 09:15:00 +0000".to_owned(),
+            checkpoint_id: Some("d5bd4941cf95".to_owned()),
+        }]
+    }
+
+    #[test]
+    fn build_filters_by_branch() {
+        let checkpoints = [
+            sample_checkpoint("d5bd4941cf95", "main"),
+            sample_checkpoint("a1b2c3d4e5f6", "feature"),
+        ];
+
+        let list = CheckpointList::build(&checkpoints, &sample_commits(), Some("main"));
+
+        assert_eq!(
+            list,
+            CheckpointList {
+                checkpoints: vec![CheckpointSummary {
+                    checkpoint_id: "d5bd4941cf95".to_owned(),
+                    branch: "main".to_owned(),
+                    strategy: "manual-commit".to_owned(),
+                    created_at: Some("2026-02-22T08:20:00Z".to_owned()),
+                    sessions: vec![SessionSummary {
+                        session_id: "s-1".to_owned(),
+                        agent: "Claude Code".to_owned(),
+                        created_at: "2026-02-22T08:20:00Z".to_owned(),
+                    }],
+                    files_touched: vec!["src/a.rs".to_owned(), "src/b.rs".to_owned()],
+                    token_usage: TokenUsage {
+                        input_tokens: 100,
+                        output_tokens: 20,
+                        ..TokenUsage::default()
+                    },
+                    commits: vec![CommitRef {
+                        hash: "c04a441".to_owned(),
+                        subject: "redesign schema".to_owned(),
+                    }],
+                }],
+                total: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn write_text_prints_one_line_per_checkpoint() {
+        let checkpoints = [sample_checkpoint("d5bd4941cf95", "main")];
+        let list = CheckpointList::build(&checkpoints, &sample_commits(), None);
+
+        let mut out = Vec::new();
+        list.write_text(&mut out, TimeStyle::Utc).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "d5bd4941cf95  main  2026-02-22T08:20:00Z  Claude Code  2 files  redesign schema\n\
+             1 checkpoint\n",
+        );
+    }
+
+    #[test]
+    fn build_sorts_newest_first() {
+        let mut old = sample_checkpoint("00aaaaaaaaaa", "main");
+        old.sessions[0].created_at = "2026-01-01T00:00:00Z".to_owned();
+        let mut new = sample_checkpoint("abcdef012345", "main");
+        new.sessions[0].created_at = "2026-10-10T09:00:00+09:00".to_owned();
+        let mut empty = sample_checkpoint("0123456789ab", "main");
+        empty.sessions.clear();
+
+        let list = CheckpointList::build(&[empty, old, new], &[], None);
+
+        let ids: Vec<_> = list
+            .checkpoints
+            .iter()
+            .map(|cp| cp.checkpoint_id.as_str())
+            .collect();
+        assert_eq!(ids, ["abcdef012345", "00aaaaaaaaaa", "0123456789ab"]);
+    }
+
+    #[test]
+    fn write_text_without_commit_or_files() {
+        let mut cp = sample_checkpoint("d5bd4941cf95", "main");
+        cp.files_touched.truncate(1);
+        let list = CheckpointList::build(&[cp], &[], None);
+
+        let mut out = Vec::new();
+        list.write_text(&mut out, TimeStyle::Utc).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "d5bd4941cf95  main  2026-02-22T08:20:00Z  Claude Code  1 file\n\
+             1 checkpoint\n",
+        );
+    }
+
+    #[test]
+    fn write_json_matches_documented_shape() {
+        let checkpoints = [sample_checkpoint("d5bd4941cf95", "main")];
+        let list = CheckpointList::build(&checkpoints, &sample_commits(), None);
+
+        let mut out = Vec::new();
+        list.write_json(&mut out).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "checkpoints": [{
+                    "checkpoint_id": "d5bd4941cf95",
+                    "branch": "main",
+                    "strategy": "manual-commit",
+                    "created_at": "2026-02-22T08:20:00Z",
+                    "sessions": [{
+                        "session_id": "s-1",
+                        "agent": "Claude Code",
+                        "created_at": "2026-02-22T08:20:00Z",
+                    }],
+                    "files_touched": ["src/a.rs", "src/b.rs"],
+                    "token_usage": {
+                        "input_tokens": 100,
+                        "cache_creation_tokens": 0,
+                        "cache_read_tokens": 0,
+                        "output_tokens": 20,
+                        "api_call_count": 0,
+                    },
+                    "commits": [{ "hash": "c04a441", "subject": "redesign schema" }],
+                }],
+                "total": 1,
+            })
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod export;
+pub mod list;
+pub mod paths;
+pub mod show;
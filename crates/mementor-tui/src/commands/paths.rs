@@ -0,0 +1,323 @@
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use mementor_lib::context::MementorContext;
+use mementor_lib::entire::{checkpoint, cli};
+use mementor_lib::git::branch::branch_exists;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Resolved runtime locations, as printed by `mementor paths`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PathsReport {
+    pub project_root: String,
+    pub cwd: String,
+    /// `"primary"` or `"linked"`.
+    pub worktree: &'static str,
+    pub checkpoint_branch: String,
+    pub checkpoint_branch_exists: bool,
+    pub entire_cli_available: bool,
+    pub config_files: Vec<ConfigFile>,
+    pub hook_files: Vec<HookFile>,
+}
+
+/// A configuration file that was considered, and whether it exists.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ConfigFile {
+    pub path: String,
+    pub exists: bool,
+}
+
+/// A Claude Code settings file and the hook commands it registers.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct HookFile {
+    pub path: String,
+    pub exists: bool,
+    /// `entire hooks claude-code ...` commands.
+    pub entire_hooks: Vec<String>,
+    /// `mementor hook ...` commands left from before the TUI pivot. mementor
+    /// has no `hook` subcommand anymore, so these fail with exit code 2.
+    pub stale_mementor_hooks: Vec<String>,
+}
+
+impl HookFile {
+    fn read(path: &Path) -> Self {
+        let commands = match std::fs::read_to_string(path) {
+            Ok(json) => match serde_json::from_str::<Value>(&json) {
+                Ok(settings) => hook_commands(&settings),
+                Err(e) => {
+                    tracing::warn!("failed to parse {}: {e}", path.display());
+                    Vec::new()
+                }
+            },
+            Err(_) => Vec::new(),
+        };
+
+        Self {
+            path: display(path),
+            exists: path.is_file(),
+            entire_hooks: commands
+                .iter()
+                .filter(|c| c.starts_with("entire hooks claude-code"))
+                .cloned()
+                .collect(),
+            stale_mementor_hooks: commands
+                .into_iter()
+                .filter(|c| c.starts_with("mementor hook"))
+                .collect(),
+        }
+    }
+}
+
+impl PathsReport {
+    /// Collect the report for the given context.
+    pub async fn collect(ctx: &MementorContext) -> Self {
+        let config_files = ctx
+            .entire_settings_files()
+            .iter()
+            .map(|path| ConfigFile {
+                path: display(path),
+                exists: path.is_file(),
+            })
+            .collect();
+        let hook_files = ctx
+            .claude_settings_files()
+            .iter()
+            .map(|path| HookFile::read(path))
+            .collect();
+
+        Self {
+            project_root: display(ctx.project_root()),
+            cwd: display(ctx.cwd()),
+            worktree: if ctx.is_linked_worktree() {
+                "linked"
+            } else {
+                "primary"
+            },
+            checkpoint_branch: checkpoint::BRANCH.to_owned(),
+            checkpoint_branch_exists: branch_exists(checkpoint::BRANCH).await,
+            entire_cli_available: cli::is_available().await,
+            config_files,
+            hook_files,
+        }
+    }
+
+    /// Write the report as aligned `label: value` lines.
+    pub fn write_text(&self, out: &mut impl Write) -> Result<()> {
+        writeln!(out, "project root:       {}", self.project_root)?;
+        writeln!(out, "working directory:  {}", self.cwd)?;
+        writeln!(out, "worktree:           {}", self.worktree)?;
+        writeln!(
+            out,
+            "checkpoint branch:  {} ({})",
+            self.checkpoint_branch,
+            found(self.checkpoint_branch_exists),
+        )?;
+        writeln!(
+            out,
+            "entire CLI:         {}",
+            if self.entire_cli_available {
+                "available"
+            } else {
+                "not found on PATH"
+            },
+        )?;
+        writeln!(out, "config files:")?;
+        for file in &self.config_files {
+            writeln!(out, "  {} ({})", file.path, found(file.exists))?;
+        }
+        writeln!(out, "hook files:")?;
+        for file in &self.hook_files {
+            if file.exists {
+                writeln!(
+                    out,
+                    "  {} (found, entire hooks: {})",
+                    file.path,
+                    file.entire_hooks.len(),
+                )?;
+            } else {
+                writeln!(out, "  {} (missing)", file.path)?;
+            }
+            for command in &file.stale_mementor_hooks {
+                writeln!(out, "    stale: {command}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the report as pretty-printed JSON.
+    pub fn write_json(&self, out: &mut impl Write) -> Result<()> {
+        serde_json::to_writer_pretty(&mut *out, self)?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
+
+/// Run `mementor paths`, writing to `out`.
+pub async fn run(ctx: &MementorContext, json: bool, out: &mut impl Write) -> Result<()> {
+    let report = PathsReport::collect(ctx).await;
+    if json {
+        report.write_json(out)
+    } else {
+        report.write_text(out)
+    }
+}
+
+/// Every hook command in a Claude Code settings file, grouped by event name.
+///
+/// Hooks are registered as `hooks.<Event>[].hooks[].command`.
+fn hook_commands(settings: &Value) -> Vec<String> {
+    let Some(events) = settings.get("hooks").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    events
+        .values()
+        .filter_map(Value::as_array)
+        .flatten()
+        .filter_map(|matcher| matcher.get("hooks").and_then(Value::as_array))
+        .flatten()
+        .filter_map(|hook| hook.get("command").and_then(Value::as_str))
+        .map(str::to_owned)
+        .collect()
+}
+
+fn display(path: &Path) -> String {
+    path.display().to_string()
+}
+
+fn found(exists: bool) -> &'static str {
+    if exists { "found" } else { "missing" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> PathsReport {
+        PathsReport {
+            project_root: "/repo".to_owned(),
+            cwd: "/repo-wt/src".to_owned(),
+            worktree: "linked",
+            checkpoint_branch: "entire/checkpoints/v1".to_owned(),
+            checkpoint_branch_exists: true,
+            entire_cli_available: false,
+            config_files: vec![
+                ConfigFile {
+                    path: "/repo/.entire/settings.json".to_owned(),
+                    exists: true,
+                },
+                ConfigFile {
+                    path: "/repo/.entire/settings.local.json".to_owned(),
+                    exists: false,
+                },
+            ],
+            hook_files: vec![
+                HookFile {
+                    path: "/repo/.claude/settings.json".to_owned(),
+                    exists: true,
+                    entire_hooks: vec!["entire hooks claude-code stop".to_owned()],
+                    stale_mementor_hooks: vec!["mementor hook stop".to_owned()],
+                },
+                HookFile {
+                    path: "/repo/.claude/settings.local.json".to_owned(),
+                    exists: false,
+                    entire_hooks: Vec::new(),
+                    stale_mementor_hooks: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn write_text_lists_all_locations() {
+        let mut out = Vec::new();
+        sample_report().write_text(&mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "project root:       /repo\n\
+             working directory:  /repo-wt/src\n\
+             worktree:           linked\n\
+             checkpoint branch:  entire/checkpoints/v1 (found)\n\
+             entire CLI:         not found on PATH\n\
+             config files:\n  \
+             /repo/.entire/settings.json (found)\n  \
+             /repo/.entire/settings.local.json (missing)\n\
+             hook files:\n  \
+             /repo/.claude/settings.json (found, entire hooks: 1)\n    \
+             stale: mementor hook stop\n  \
+             /repo/.claude/settings.local.json (missing)\n",
+        );
+    }
+
+    #[test]
+    fn write_json_round_trips_fields() {
+        let mut out = Vec::new();
+        sample_report().write_json(&mut out).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "project_root": "/repo",
+                "cwd": "/repo-wt/src",
+                "worktree": "linked",
+                "checkpoint_branch": "entire/checkpoints/v1",
+                "checkpoint_branch_exists": true,
+                "entire_cli_available": false,
+                "config_files": [
+                    { "path": "/repo/.entire/settings.json", "exists": true },
+                    { "path": "/repo/.entire/settings.local.json", "exists": false },
+                ],
+                "hook_files": [
+                    {
+                        "path": "/repo/.claude/settings.json",
+                        "exists": true,
+                        "entire_hooks": ["entire hooks claude-code stop"],
+                        "stale_mementor_hooks": ["mementor hook stop"],
+                    },
+                    {
+                        "path": "/repo/.claude/settings.local.json",
+                        "exists": false,
+                        "entire_hooks": [],
+                        "stale_mementor_hooks": [],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn hook_commands_collects_every_event() {
+        let settings = serde_json::json!({
+            "hooks": {
+                "Stop": [{
+                    "matcher": "",
+                    "hooks": [
+                        { "type": "command", "command": "mementor hook stop" },
+                        { "type": "command", "command": "entire hooks claude-code stop" },
+                    ],
+                }],
+                "PreToolUse": [{
+                    "matcher": "Task",
+                    "hooks": [{ "type": "command", "command": "entire hooks claude-code pre-task" }],
+                }],
+            },
+        });
+
+        assert_eq!(
+            hook_commands(&settings),
+            [
+                "entire hooks claude-code pre-task",
+                "mementor hook stop",
+                "entire hooks claude-code stop",
+            ]
+        );
+    }
+
+    #[test]
+    fn hook_commands_without_hooks_is_empty() {
+        assert!(hook_commands(&serde_json::json!({ "env": {} })).is_empty());
+    }
+}
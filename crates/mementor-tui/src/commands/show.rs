@@ -0,0 +1,246 @@
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use mementor_lib::cache::DataCache;
+use mementor_lib::git::log::CommitInfo;
+use mementor_lib::model::{Attribution, CheckpointMeta, TokenUsage};
+use serde::Serialize;
+
+use super::list::linked_commits;
+use crate::views::time_utils::{self, TimeStyle};
+
+/// A single checkpoint, as printed by `mementor show`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CheckpointDetail {
+    pub checkpoint_id: String,
+    pub branch: String,
+    pub strategy: String,
+    /// Creation time of the first session, if any.
+    pub created_at: Option<String>,
+    pub sessions: Vec<SessionDetail>,
+    pub files_touched: Vec<String>,
+    pub token_usage: TokenUsage,
+    pub commits: Vec<CommitDetail>,
+}
+
+/// A session within a [`CheckpointDetail`].
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SessionDetail {
+    pub session_id: String,
+    pub agent: String,
+    pub created_at: String,
+    pub token_usage: TokenUsage,
+    pub attribution: Attribution,
+}
+
+/// A commit linked to the checkpoint.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CommitDetail {
+    pub hash: String,
+    pub short_hash: String,
+    pub subject: String,
+    pub author: String,
+    /// Commit date as RFC 3339 in UTC, normalized from git's `%ai` output.
+    pub date: String,
+}
+
+impl CheckpointDetail {
+    /// Build the detail for `cp`, resolving its linked commits from `commits`.
+    pub fn build(cp: &CheckpointMeta, commits: &[CommitInfo]) -> Self {
+        Self {
+            checkpoint_id: cp.checkpoint_id.clone(),
+            branch: cp.branch.clone(),
+            strategy: cp.strategy.clone(),
+            created_at: cp.sessions.first().map(|s| s.created_at.clone()),
+            sessions: cp
+                .sessions
+                .iter()
+                .map(|s| SessionDetail {
+                    session_id: s.session_id.clone(),
+                    agent: s.agent.clone(),
+                    created_at: s.created_at.clone(),
+                    token_usage: s.token_usage.clone(),
+                    attribution: s.initial_attribution.clone(),
+                })
+                .collect(),
+            files_touched: cp.files_touched.clone(),
+            token_usage: cp.token_usage.clone(),
+            commits: linked_commits(cp, commits)
+                .map(|c| CommitDetail {
+                    hash: c.hash.clone(),
+                    short_hash: c.short_hash.clone(),
+                    subject: c.subject.clone(),
+                    author: c.author.clone(),
+                    date: time_utils::format_timestamp(&c.date, TimeStyle::Utc),
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the checkpoint as labelled sections.
+    pub fn write_text(&self, out: &mut impl Write, time_style: TimeStyle) -> Result<()> {
+        writeln!(out, "checkpoint:  {}", self.checkpoint_id)?;
+        writeln!(out, "branch:      {}", self.branch)?;
+        writeln!(out, "strategy:    {}", self.strategy)?;
+        if let Some(created_at) = &self.created_at {
+            writeln!(
+                out,
+                "created:     {}",
+                time_utils::format_timestamp(created_at, time_style)
+            )?;
+        }
+        writeln!(
+            out,
+            "tokens:      {} in / {} out",
+            self.token_usage.input_tokens, self.token_usage.output_tokens,
+        )?;
+
+        writeln!(out, "sessions:")?;
+        for s in &self.sessions {
+            writeln!(
+                out,
+                "  {}  {}  {}  {} in / {} out",
+                s.session_id,
+                s.agent,
+                time_utils::format_timestamp(&s.created_at, time_style),
+                s.token_usage.input_tokens,
+                s.token_usage.output_tokens,
+            )?;
+        }
+
+        writeln!(out, "files touched:")?;
+        for path in &self.files_touched {
+            writeln!(out, "  {path}")?;
+        }
+
+        writeln!(out, "commits:")?;
+        for c in &self.commits {
+            writeln!(
+                out,
+                "  {}  {}  {}  {}",
+                c.short_hash,
+                time_utils::format_timestamp(&c.date, time_style),
+                c.author,
+                c.subject,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Write the checkpoint as pretty-printed JSON.
+    pub fn write_json(&self, out: &mut impl Write) -> Result<()> {
+        serde_json::to_writer_pretty(&mut *out, self)?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
+
+/// Load the data cache for `checkpoint_id` and return it with the checkpoint.
+///
+/// Commits are linked from the log of the branch the checkpoint was recorded
+/// on, like `list --branch`, so a checkpoint from another branch still shows
+/// its commits. The current branch is kept when the recorded branch links
+/// none, e.g. after it was merged and deleted.
+pub(crate) async fn load_checkpoint(
+    current_branch: &str,
+    checkpoint_id: &str,
+) -> Result<(DataCache, CheckpointMeta)> {
+    let cache = DataCache::initialize(current_branch).await?;
+    let cp = find_checkpoint(&cache, checkpoint_id)?;
+    if cp.branch.is_empty() || cp.branch == current_branch {
+        return Ok((cache, cp));
+    }
+
+    let branch_cache = DataCache::initialize(&cp.branch).await?;
+    let branch_cp = find_checkpoint(&branch_cache, checkpoint_id)?;
+    if branch_cp.commit_hashes.is_empty() && !cp.commit_hashes.is_empty() {
+        Ok((cache, cp))
+    } else {
+        Ok((branch_cache, branch_cp))
+    }
+}
+
+fn find_checkpoint(cache: &DataCache, checkpoint_id: &str) -> Result<CheckpointMeta> {
+    cache
+        .checkpoints()
+        .iter()
+        .find(|cp| cp.checkpoint_id == checkpoint_id)
+        .cloned()
+        .with_context(|| format!("checkpoint {checkpoint_id} not found"))
+}
+
+/// Run `mementor show <checkpoint-id>`, writing to `out`.
+pub async fn run(
+    current_branch: &str,
+    checkpoint_id: &str,
+    json: bool,
+    time_style: TimeStyle,
+    out: &mut impl Write,
+) -> Result<()> {
+    let (cache, cp) = load_checkpoint(current_branch, checkpoint_id).await?;
+
+    let detail = CheckpointDetail::build(&cp, cache.commits());
+    if json {
+        detail.write_json(out)
+    } else {
+        detail.write_text(out, time_style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::tests::{sample_checkpoint, sample_commits};
+
+    #[test]
+    fn write_text_lists_sessions_files_and_commits() {
+        let cp = sample_checkpoint("d5bd4941cf95", "main");
+        let detail = CheckpointDetail::build(&cp, &sample_commits());
+
+        let mut out = Vec::new();
+        detail.write_text(&mut out, TimeStyle::Utc).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "checkpoint:  d5bd4941cf95\n\
+             branch:      main\n\
+             strategy:    manual-commit\n\
+             created:     2026-02-22T08:20:00Z\n\
+             tokens:      100 in / 20 out\n\
+             sessions:\n  \
+             s-1  Claude Code  2026-02-22T08:20:00Z  0 in / 0 out\n\
+             files touched:\n  \
+             src/a.rs\n  \
+             src/b.rs\n\
+             commits:\n  \
+             c04a441  2026-02-22T09:15:00Z  Alice  redesign schema\n",
+        );
+    }
+
+    #[test]
+    fn build_normalizes_commit_date_to_rfc3339() {
+        let cp = sample_checkpoint("d5bd4941cf95", "main");
+        let detail = CheckpointDetail::build(&cp, &sample_commits());
+
+        assert_eq!(
+            detail.commits,
+            vec![CommitDetail {
+                hash: "c04a441f".to_owned(),
+                short_hash: "c04a441".to_owned(),
+                subject: "redesign schema".to_owned(),
+                author: "Alice".to_owned(),
+                date: "2026-02-22T09:15:00Z".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn unlinked_commits_are_omitted() {
+        let mut cp = sample_checkpoint("d5bd4941cf95", "main");
+        cp.commit_hashes = vec!["not-in-log".to_owned()];
+
+        let detail = CheckpointDetail::build(&cp, &sample_commits());
+
+        assert!(detail.commits.is_empty());
+    }
+}
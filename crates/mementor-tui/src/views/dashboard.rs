@@ -3,6 +3,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem};
 
 use crate::app::App;
+use crate::views::time_utils;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let checkpoints = app.cache.checkpoints();
@@ -31,7 +32,9 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
             let date_str = cp
                 .sessions
                 .first()
-                .map_or_else(String::new, |s| format_relative_time(&s.created_at));
+                .map_or_else(String::new, |s| {
+                    time_utils::format_timestamp(&s.created_at, app.time_style)
+                });
 
             let agent = cp
                 .sessions
@@ -92,33 +95,3 @@ fn format_tokens(total: u64) -> String {
         format!("{total} tok")
     }
 }
-
-fn format_relative_time(iso_str: &str) -> String {
-    // Parse ISO 8601 timestamp and compute relative time.
-    // Use jiff for parsing, falling back to raw string on failure.
-    let Ok(timestamp) = iso_str.parse::<jiff::Timestamp>() else {
-        return iso_str.to_owned();
-    };
-    let now = jiff::Timestamp::now();
-    let span = timestamp.until(now);
-    let Ok(span) = span else {
-        return iso_str.to_owned();
-    };
-
-    let total_seconds = span.get_seconds();
-    let hours = total_seconds / 3600;
-    let days = hours / 24;
-
-    if days > 0 {
-        format!("{days}d ago")
-    } else if hours > 0 {
-        format!("{hours}h ago")
-    } else {
-        let mins = total_seconds / 60;
-        if mins > 0 {
-            format!("{mins}m ago")
-        } else {
-            "just now".to_owned()
-        }
-    }
-}
@@ -9,6 +9,8 @@ use ratatui::Frame;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
 
+use super::time_utils::{self, TimeStyle};
+
 /// Which panel has keyboard focus in the detail view.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DetailPanel {
@@ -102,6 +104,7 @@ pub fn render<S: BuildHasher>(
     commits: &[CommitInfo],
     file_statuses: &HashMap<String, FileStatus, S>,
     transcript_entries: Option<&[TranscriptEntry]>,
+    time_style: TimeStyle,
 ) {
     // Header.
     let subject = checkpoint
@@ -130,7 +133,7 @@ pub fn render<S: BuildHasher>(
         commits,
         file_statuses,
     );
-    render_transcript_pane(frame, h_chunks[1], state, transcript_entries);
+    render_transcript_pane(frame, h_chunks[1], state, transcript_entries, time_style);
 }
 
 fn render_sidebar<S: BuildHasher>(
@@ -251,6 +254,7 @@ fn render_transcript_pane(
     area: Rect,
     state: &DetailState,
     transcript_entries: Option<&[TranscriptEntry]>,
+    time_style: TimeStyle,
 ) {
     let border_style = if state.focus == DetailPanel::Transcript {
         Style::default().fg(Color::Yellow)
@@ -284,7 +288,7 @@ fn render_transcript_pane(
     for entry in entries {
         match entry {
             TranscriptEntry::Message(msg) => {
-                render_message_lines(&mut lines, msg);
+                render_message_lines(&mut lines, msg, time_style);
             }
             TranscriptEntry::PrLink {
                 pr_number, pr_url, ..
@@ -300,6 +304,12 @@ fn render_transcript_pane(
                     Style::default().fg(Color::DarkGray),
                 )));
             }
+            TranscriptEntry::Malformed { line, error, .. } => {
+                lines.push(Line::from(Span::styled(
+                    format!("  [Malformed line {line}: {error}]"),
+                    Style::default().fg(Color::Red),
+                )));
+            }
             TranscriptEntry::Progress(_) | TranscriptEntry::Other(_) => {}
         }
         lines.push(Line::from(""));
@@ -311,7 +321,11 @@ fn render_transcript_pane(
     frame.render_widget(paragraph, inner);
 }
 
-fn render_message_lines(lines: &mut Vec<Line>, msg: &mementor_lib::model::TranscriptMessage) {
+fn render_message_lines(
+    lines: &mut Vec<Line>,
+    msg: &mementor_lib::model::TranscriptMessage,
+    time_style: TimeStyle,
+) {
     // Header line.
     let (role_label, role_style) = match msg.role {
         MessageRole::User => (
@@ -328,7 +342,9 @@ fn render_message_lines(lines: &mut Vec<Line>, msg: &mementor_lib::model::Transc
         ),
     };
 
-    let timestamp_str = msg.timestamp.as_deref().unwrap_or("");
+    let timestamp_str = msg.timestamp.as_deref().map_or_else(String::new, |ts| {
+        time_utils::format_message_timestamp(ts, time_style)
+    });
     lines.push(Line::from(vec![
         Span::styled(format!("  {role_label}"), role_style),
         Span::styled(
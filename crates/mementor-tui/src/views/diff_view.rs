@@ -74,7 +74,7 @@ pub fn render(
     let diff = &diffs[state.file_index];
 
     // Build the title: header + file info.
-    let file_label = format!("[{}/{}] {}", state.file_index + 1, diffs.len(), diff.path,);
+    let file_label = format!("[{}/{}] {}", state.file_index + 1, diffs.len(), diff.path);
     let title = format!(" {header}  {file_label} ");
 
     // Build rendered lines from hunks.
@@ -5,6 +5,8 @@ use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 
 use mementor_lib::git::log::CommitInfo;
 
+use super::time_utils::{self, TimeStyle};
+
 /// State for the git log view.
 pub struct GitLogState {
     /// Selection state for the commit list.
@@ -43,7 +45,13 @@ pub enum GitLogAction {
 }
 
 /// Render the git log view.
-pub fn render(frame: &mut Frame, area: Rect, state: &mut GitLogState, commits: &[CommitInfo]) {
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    state: &mut GitLogState,
+    commits: &[CommitInfo],
+    time_style: TimeStyle,
+) {
     if commits.is_empty() {
         let empty = ratatui::widgets::Paragraph::new("No commits found.")
             .block(Block::default().borders(Borders::ALL).title(" Git Log "));
@@ -71,7 +79,7 @@ pub fn render(frame: &mut Frame, area: Rect, state: &mut GitLogState, commits: &
             let mut spans2 = vec![
                 Span::raw("  "),
                 Span::styled(
-                    format_short_date(&commit.date),
+                    time_utils::format_timestamp(&commit.date, time_style),
                     Style::default().fg(Color::DarkGray),
                 ),
                 Span::raw("  "),
@@ -151,28 +159,6 @@ pub fn handle_key(key: KeyEvent, state: &mut GitLogState, commits: &[CommitInfo]
     }
 }
 
-/// Format a git date string (e.g. "2026-02-20 10:30:00 +0900") into a
-/// shorter display form (e.g. "2026-02-20 10:30").
-fn format_short_date(date: &str) -> String {
-    // Parse with jiff to validate and extract components, falling back to raw
-    // string on failure.
-    jiff::fmt::strtime::parse("%Y-%m-%d %H:%M:%S %z", date)
-        .and_then(|bdt| bdt.to_datetime())
-        .map_or_else(
-            |_| date.to_owned(),
-            |dt| {
-                format!(
-                    "{:04}-{:02}-{:02} {:02}:{:02}",
-                    dt.year(),
-                    dt.month(),
-                    dt.day(),
-                    dt.hour(),
-                    dt.minute(),
-                )
-            },
-        )
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,19 +184,6 @@ mod tests {
         ]
     }
 
-    #[test]
-    fn format_short_date_trims() {
-        assert_eq!(
-            format_short_date("2026-02-20 10:30:00 +0900"),
-            "2026-02-20 10:30"
-        );
-    }
-
-    #[test]
-    fn format_short_date_short_input() {
-        assert_eq!(format_short_date("2026-02-20"), "2026-02-20");
-    }
-
     #[test]
     fn handle_key_esc_returns_back() {
         let mut state = GitLogState::new();
@@ -5,4 +5,5 @@ pub mod diff_view;
 pub mod git_log;
 pub mod status_bar;
 pub mod text_utils;
+pub mod time_utils;
 pub mod transcript;
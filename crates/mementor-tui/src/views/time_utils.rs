@@ -0,0 +1,189 @@
+use jiff::Timestamp;
+use jiff::tz::TimeZone;
+
+/// How timestamps are rendered across all views.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeStyle {
+    /// Relative to now (e.g. `3d ago`).
+    #[default]
+    Relative,
+    /// Absolute ISO 8601 in the system time zone (e.g.
+    /// `2026-02-20T10:30:00+09:00`).
+    Iso,
+    /// Absolute ISO 8601 in UTC (e.g. `2026-02-20T01:30:00Z`).
+    Utc,
+}
+
+/// Format a raw timestamp string for display in the given style.
+///
+/// Accepts RFC 3339 timestamps (transcripts, checkpoint metadata) and git's
+/// `%ai` format (`2026-02-20 10:30:00 +0900`). Unparseable input is returned
+/// unchanged.
+pub fn format_timestamp(raw: &str, style: TimeStyle) -> String {
+    format_timestamp_at(raw, style, Timestamp::now(), &TimeZone::system())
+}
+
+/// Like [`format_timestamp`], with an explicit "now" and local time zone.
+fn format_timestamp_at(raw: &str, style: TimeStyle, now: Timestamp, tz: &TimeZone) -> String {
+    let Some(timestamp) = parse_timestamp(raw) else {
+        return raw.to_owned();
+    };
+
+    match style {
+        TimeStyle::Relative => format_relative(timestamp, now),
+        TimeStyle::Iso => timestamp
+            .to_zoned(tz.clone())
+            .strftime("%Y-%m-%dT%H:%M:%S%:z")
+            .to_string(),
+        TimeStyle::Utc => timestamp.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+    }
+}
+
+/// Format a transcript message timestamp for a message header.
+///
+/// Messages in one session are usually minutes apart, so relative times
+/// (`2h ago` on every header) cannot tell them apart. In
+/// [`TimeStyle::Relative`] the local time of day (`10:30:00`) is shown
+/// instead; the absolute styles match [`format_timestamp`].
+pub fn format_message_timestamp(raw: &str, style: TimeStyle) -> String {
+    format_message_timestamp_at(raw, style, Timestamp::now(), &TimeZone::system())
+}
+
+/// Like [`format_message_timestamp`], with an explicit "now" and local time
+/// zone.
+fn format_message_timestamp_at(
+    raw: &str,
+    style: TimeStyle,
+    now: Timestamp,
+    tz: &TimeZone,
+) -> String {
+    match (style, parse_timestamp(raw)) {
+        (TimeStyle::Relative, Some(timestamp)) => timestamp
+            .to_zoned(tz.clone())
+            .strftime("%H:%M:%S")
+            .to_string(),
+        _ => format_timestamp_at(raw, style, now, tz),
+    }
+}
+
+/// Parse an RFC 3339 timestamp, falling back to git's `%ai` date format.
+pub(crate) fn parse_timestamp(raw: &str) -> Option<Timestamp> {
+    raw.parse::<Timestamp>().ok().or_else(|| {
+        jiff::fmt::strtime::parse("%Y-%m-%d %H:%M:%S %z", raw)
+            .and_then(|bdt| bdt.to_timestamp())
+            .ok()
+    })
+}
+
+fn format_relative(timestamp: Timestamp, now: Timestamp) -> String {
+    let total_seconds = now.duration_since(timestamp).as_secs();
+    let hours = total_seconds / 3600;
+    let days = hours / 24;
+
+    if days > 0 {
+        format!("{days}d ago")
+    } else if hours > 0 {
+        format!("{hours}h ago")
+    } else {
+        let mins = total_seconds / 60;
+        if mins > 0 {
+            format!("{mins}m ago")
+        } else {
+            "just now".to_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> Timestamp {
+        "2026-02-26T12:00:00Z".parse().unwrap()
+    }
+
+    fn kst() -> TimeZone {
+        TimeZone::fixed(jiff::tz::offset(9))
+    }
+
+    fn format(raw: &str, style: TimeStyle) -> String {
+        format_timestamp_at(raw, style, now(), &kst())
+    }
+
+    #[test]
+    fn relative_days_hours_minutes() {
+        assert_eq!(
+            format("2026-02-23T12:00:00Z", TimeStyle::Relative),
+            "3d ago"
+        );
+        assert_eq!(
+            format("2026-02-26T07:30:00Z", TimeStyle::Relative),
+            "4h ago"
+        );
+        assert_eq!(
+            format("2026-02-26T11:15:00Z", TimeStyle::Relative),
+            "45m ago"
+        );
+        assert_eq!(
+            format("2026-02-26T11:59:30Z", TimeStyle::Relative),
+            "just now"
+        );
+    }
+
+    #[test]
+    fn relative_future_timestamp_is_just_now() {
+        assert_eq!(
+            format("2026-02-27T12:00:00Z", TimeStyle::Relative),
+            "just now"
+        );
+    }
+
+    #[test]
+    fn iso_uses_local_time_zone() {
+        assert_eq!(
+            format("2026-02-20T01:30:00Z", TimeStyle::Iso),
+            "2026-02-20T10:30:00+09:00"
+        );
+    }
+
+    #[test]
+    fn utc_normalizes_offset() {
+        assert_eq!(
+            format("2026-02-20T10:30:00+09:00", TimeStyle::Utc),
+            "2026-02-20T01:30:00Z"
+        );
+    }
+
+    #[test]
+    fn git_date_format_is_parsed() {
+        assert_eq!(
+            format("2026-02-20 10:30:00 +0900", TimeStyle::Utc),
+            "2026-02-20T01:30:00Z"
+        );
+        assert_eq!(
+            format("2026-02-20 10:30:00 +0900", TimeStyle::Relative),
+            "6d ago"
+        );
+    }
+
+    #[test]
+    fn message_timestamp_is_time_of_day_in_relative_mode() {
+        let format = |raw, style| format_message_timestamp_at(raw, style, now(), &kst());
+
+        assert_eq!(
+            format("2026-02-20T01:30:00Z", TimeStyle::Relative),
+            "10:30:00"
+        );
+        assert_eq!(
+            format("2026-02-20T01:30:00Z", TimeStyle::Utc),
+            "2026-02-20T01:30:00Z"
+        );
+        assert_eq!(format("garbage", TimeStyle::Relative), "garbage");
+    }
+
+    #[test]
+    fn unparseable_input_is_returned_unchanged() {
+        assert_eq!(format("2026-02-20", TimeStyle::Relative), "2026-02-20");
+        assert_eq!(format("", TimeStyle::Utc), "");
+    }
+}
@@ -6,6 +6,8 @@ use ratatui::Frame;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
+use super::time_utils::{self, TimeStyle};
+
 // ---------------------------------------------------------------------------
 // Public types
 // ---------------------------------------------------------------------------
@@ -93,9 +95,10 @@ pub fn render(
     area: Rect,
     state: &mut TranscriptViewState,
     entries: &[TranscriptEntry],
+    time_style: TimeStyle,
 ) {
     // Build all displayable lines from transcript entries.
-    let (lines, tools) = build_lines(entries, state);
+    let (lines, tools) = build_lines(entries, state, time_style);
     state.total_lines = lines.len();
     state.tool_line_map = tools.iter().map(|t| t.line_index).collect();
 
@@ -316,6 +319,7 @@ struct ToolEntry {
 fn build_lines(
     entries: &[TranscriptEntry],
     state: &TranscriptViewState,
+    time_style: TimeStyle,
 ) -> (Vec<Line<'static>>, Vec<ToolEntry>) {
     let mut lines: Vec<Line<'static>> = Vec::new();
     let mut tools: Vec<ToolEntry> = Vec::new();
@@ -324,7 +328,14 @@ fn build_lines(
     for entry in entries {
         match entry {
             TranscriptEntry::Message(msg) => {
-                append_message(msg, state, &mut lines, &mut tools, &mut tool_seq);
+                append_message(
+                    msg,
+                    state,
+                    time_style,
+                    &mut lines,
+                    &mut tools,
+                    &mut tool_seq,
+                );
             }
             TranscriptEntry::FileHistorySnapshot { files } => {
                 lines.push(Line::from(Span::styled(
@@ -351,6 +362,17 @@ fn build_lines(
                     )));
                 }
             }
+            TranscriptEntry::Malformed { line, raw, error } => {
+                lines.push(Line::from(Span::styled(
+                    format!("--- malformed line {line}: {error} ---"),
+                    Style::default().fg(Color::Red),
+                )));
+                lines.push(Line::from(Span::styled(
+                    truncate(raw, 80),
+                    Style::default().fg(Color::DarkGray),
+                )));
+                lines.push(Line::from(""));
+            }
             TranscriptEntry::Other(_) => {}
         }
     }
@@ -361,6 +383,7 @@ fn build_lines(
 fn append_message(
     msg: &TranscriptMessage,
     state: &TranscriptViewState,
+    time_style: TimeStyle,
     lines: &mut Vec<Line<'static>>,
     tools: &mut Vec<ToolEntry>,
     tool_seq: &mut usize,
@@ -380,10 +403,12 @@ fn append_message(
             "[Assistant]",
         ),
     };
-    let ts = msg.timestamp.as_deref().unwrap_or("");
+    let ts = msg.timestamp.as_deref().map_or_else(String::new, |ts| {
+        time_utils::format_message_timestamp(ts, time_style)
+    });
     lines.push(Line::from(vec![
         Span::styled(format!("{label} "), style),
-        Span::styled(ts.to_owned(), Style::default().fg(Color::DarkGray)),
+        Span::styled(ts, Style::default().fg(Color::DarkGray)),
     ]));
 
     // Content blocks.